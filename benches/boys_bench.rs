@@ -1,5 +1,5 @@
-use criterion::{black_box, criterion_group, criterion_main, Criterion, BenchmarkId};
-use boys::boys_impl::BoysFunction;
+use criterion::{black_box, criterion_group, criterion_main, Criterion, BenchmarkId, Throughput};
+use boys::boys_impl::{BoysFunction, BoysFunctionCached, BoysWorkspace, Summation};
 
 fn bench_boys_single(c: &mut Criterion) {
     let boys = BoysFunction::new(None);
@@ -42,5 +42,320 @@ fn bench_boys_mixed(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, bench_boys_single, bench_boys_array, bench_boys_mixed);
+fn bench_erf_seed(c: &mut Criterion) {
+    let boys = BoysFunction::new(None);
+    let cached = BoysFunctionCached::new(10, None).with_erf_seed_table(1000.0, 4096);
+
+    let mut group = c.benchmark_group("erf_seed");
+
+    group.bench_function("libm_erf", |b| {
+        b.iter(|| boys.eval_array(black_box(10), black_box(150.0)));
+    });
+    group.bench_function("tabulated", |b| {
+        b.iter(|| cached.eval_array(black_box(10), black_box(150.0)));
+    });
+
+    group.finish();
+}
+
+/// The series dividing by each `m + 1/2 + k` against `BoysFunctionCached`
+/// multiplying by tabulated reciprocals. Both sum with the default
+/// `Summation` and tolerance, so only the reciprocals differ.
+fn bench_series_reciprocals(c: &mut Criterion) {
+    let boys = BoysFunction::new(None);
+    let cached = BoysFunctionCached::new(10, None);
+
+    let mut group = c.benchmark_group("series_reciprocals");
+
+    for t in [5.0, 50.0, 100.0].iter() {
+        group.bench_with_input(BenchmarkId::new("divide", t), t, |b, &t| {
+            b.iter(|| boys.eval(black_box(2), black_box(t)));
+        });
+        group.bench_with_input(BenchmarkId::new("multiply", t), t, |b, &t| {
+            b.iter(|| cached.eval(black_box(2), black_box(t)));
+        });
+    }
+
+    group.finish();
+}
+
+/// Allocating, caller-buffered and workspace array evaluation, in both the
+/// series (`t = 5`) and recurrence (`t = 150`) regimes.
+fn bench_array_paths(c: &mut Criterion) {
+    let boys = BoysFunction::new(None);
+    let mut out = vec![0.0_f64; 21];
+    let mut workspace = BoysWorkspace::new();
+
+    let mut group = c.benchmark_group("array_paths");
+
+    for t in [5.0, 150.0].iter() {
+        group.bench_with_input(BenchmarkId::new("eval_array", t), t, |b, &t| {
+            b.iter(|| boys.eval_array(black_box(20), black_box(t)));
+        });
+        group.bench_with_input(BenchmarkId::new("eval_array_into", t), t, |b, &t| {
+            b.iter(|| boys.eval_array_into(black_box(20), black_box(t), &mut out));
+        });
+        group.bench_with_input(BenchmarkId::new("workspace", t), t, |b, &t| {
+            b.iter(|| {
+                black_box(workspace.eval_array(&boys, black_box(20), black_box(t)));
+            });
+        });
+    }
+
+    group.finish();
+}
+
+/// Grid tabulation at `mmax = 10` over `t` up to 191, across the series and
+/// recurrence regimes.
+fn bench_grid(c: &mut Criterion) {
+    let boys = BoysFunction::new(None);
+    let ts: Vec<f64> = (0..256).map(|i| i as f64 * 0.75).collect();
+    let mut workspace = BoysWorkspace::new();
+
+    let mut group = c.benchmark_group("grid");
+
+    group.bench_function("flat", |b| {
+        b.iter(|| boys.eval_grid(black_box(10), black_box(&ts)));
+    });
+    group.bench_function("soa", |b| {
+        b.iter(|| boys.eval_grid_soa(black_box(10), black_box(&ts)));
+    });
+    // One series plus a downward recurrence per `t`, as `simd` does in the
+    // series range but without lanes, to separate the lane speedup from the
+    // algorithmic one over `flat`.
+    group.bench_function("downward_scalar", |b| {
+        b.iter(|| {
+            for &t in black_box(&ts) {
+                black_box(boys.eval_array_stable_recur(10, t));
+            }
+        });
+    });
+    // The same recurrence into one grid, then into a reused workspace, to
+    // show what the per-`t` allocations of `downward_scalar` cost.
+    group.bench_function("downward_grid", |b| {
+        b.iter(|| boys.eval_grid_downward(black_box(10), black_box(&ts)));
+    });
+    group.bench_function("downward_workspace", |b| {
+        b.iter(|| {
+            black_box(workspace.eval_grid_downward(&boys, black_box(10), black_box(&ts)));
+        });
+    });
+    group.bench_function("simd", |b| {
+        b.iter(|| boys.eval_grid_simd(black_box(10), black_box(&ts)));
+    });
+
+    group.finish();
+}
+
+/// `BoysFunctionCached::eval` against the uncached evaluator in each regime.
+fn bench_cached_eval(c: &mut Criterion) {
+    let boys = BoysFunction::new(None);
+    let cached = BoysFunctionCached::new(10, None).with_erf_seed_table(1000.0, 4096);
+
+    let mut group = c.benchmark_group("cached_eval");
+
+    for t in [0.05, 5.0, 50.0, 150.0].iter() {
+        group.bench_with_input(BenchmarkId::new("uncached", t), t, |b, &t| {
+            b.iter(|| boys.eval(black_box(4), black_box(t)));
+        });
+        group.bench_with_input(BenchmarkId::new("cached", t), t, |b, &t| {
+            b.iter(|| cached.eval(black_box(4), black_box(t)));
+        });
+    }
+
+    group.finish();
+}
+
+/// Cached batch evaluation at `m = 4` over the Taylor-table range, scalar
+/// against the lane-wise SoA path.
+fn bench_cached_batch(c: &mut Criterion) {
+    let cached = BoysFunctionCached::new(10, None).with_taylor_table(0.1);
+    let ts: Vec<f64> = (0..1024).map(|i| 0.11 * i as f64).collect();
+
+    let mut group = c.benchmark_group("cached_batch");
+
+    group.bench_function("scalar", |b| {
+        b.iter(|| {
+            black_box(&ts)
+                .iter()
+                .map(|&t| cached.eval(black_box(4), t))
+                .collect::<Vec<f64>>()
+        });
+    });
+    group.bench_function("simd", |b| {
+        b.iter(|| cached.eval_batch_cached_simd(black_box(4), black_box(&ts)));
+    });
+
+    group.finish();
+}
+
+/// `n` pseudo-random `(m, t)` pairs from a fixed seed, with `m` uniform in
+/// `0..=8` and `t` log-uniform over `[1e-3, 1e3]`: most arguments are small,
+/// as in real integral sets dominated by diffuse pairs, with a long tail of
+/// large `t` from tight ones.
+fn realistic_requests(n: usize) -> Vec<(i32, f64)> {
+    // xorshift64*, so the mix is identical on every run and machine.
+    let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+    let mut next = move || {
+        state ^= state >> 12;
+        state ^= state << 25;
+        state ^= state >> 27;
+        state.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    };
+    (0..n)
+        .map(|_| {
+            let m = (next() % 9) as i32;
+            let u = (next() >> 11) as f64 / (1u64 << 53) as f64;
+            (m, 10.0_f64.powf(6.0 * u - 3.0))
+        })
+        .collect()
+}
+
+/// Mixed-regime `eval` throughput over `realistic_requests`.
+fn bench_realistic_mix(c: &mut Criterion) {
+    let boys = BoysFunction::new(None);
+    let requests = realistic_requests(4096);
+
+    let mut group = c.benchmark_group("realistic_mix");
+    group.throughput(Throughput::Elements(requests.len() as u64));
+
+    group.bench_function("eval", |b| {
+        b.iter(|| {
+            for &(m, t) in black_box(&requests) {
+                black_box(boys.eval(m, t));
+            }
+        });
+    });
+
+    group.finish();
+}
+
+/// The `F_0` screening path against the generic `eval(0, t)`.
+fn bench_f0(c: &mut Criterion) {
+    let boys = BoysFunction::new(None);
+
+    let mut group = c.benchmark_group("f0");
+
+    for t in [1e-4, 0.5, 5.0, 50.0, 150.0].iter() {
+        group.bench_with_input(BenchmarkId::new("eval", t), t, |b, &t| {
+            b.iter(|| boys.eval(black_box(0), black_box(t)));
+        });
+        group.bench_with_input(BenchmarkId::new("f0", t), t, |b, &t| {
+            b.iter(|| boys.f0(black_box(t)));
+        });
+    }
+
+    group.finish();
+}
+
+/// `F_0` over a screening grid up to `t = 51`: the generic batch, `f0` per
+/// element, and the table-driven `eval_f0_batch`.
+fn bench_f0_batch(c: &mut Criterion) {
+    let boys = BoysFunction::new(None);
+    let ts: Vec<f64> = (0..1024).map(|i| i as f64 * 0.05).collect();
+
+    let mut group = c.benchmark_group("f0_batch");
+    group.throughput(Throughput::Elements(ts.len() as u64));
+
+    group.bench_function("eval_batch", |b| {
+        b.iter(|| boys.eval_batch(black_box(0), black_box(&ts)));
+    });
+    group.bench_function("f0", |b| {
+        b.iter(|| black_box(&ts).iter().map(|&t| boys.f0(t)).collect::<Vec<f64>>());
+    });
+    group.bench_function("eval_f0_batch", |b| {
+        b.iter(|| boys.eval_f0_batch(black_box(&ts)));
+    });
+
+    group.finish();
+}
+
+/// `eval_branchless` against the branching `eval`, in each regime and over
+/// `realistic_requests`, whose mixed regimes defeat the branch predictor.
+fn bench_branchless(c: &mut Criterion) {
+    let boys = BoysFunction::new(None);
+    let requests = realistic_requests(4096);
+
+    let mut group = c.benchmark_group("branchless");
+
+    for t in [0.05, 5.0, 150.0].iter() {
+        group.bench_with_input(BenchmarkId::new("eval", t), t, |b, &t| {
+            b.iter(|| boys.eval(black_box(4), black_box(t)));
+        });
+        group.bench_with_input(BenchmarkId::new("branchless", t), t, |b, &t| {
+            b.iter(|| boys.eval_branchless(black_box(4), black_box(t)));
+        });
+    }
+    group.throughput(Throughput::Elements(requests.len() as u64));
+    group.bench_function("eval_mixed", |b| {
+        b.iter(|| {
+            for &(m, t) in black_box(&requests) {
+                black_box(boys.eval(m, t));
+            }
+        });
+    });
+    group.bench_function("branchless_mixed", |b| {
+        b.iter(|| {
+            for &(m, t) in black_box(&requests) {
+                black_box(boys.eval_branchless(m, t));
+            }
+        });
+    });
+
+    group.finish();
+}
+
+/// Per-call cost of each series summation in the mid range.
+fn bench_summation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("summation");
+
+    for summation in [Summation::Naive, Summation::Kahan, Summation::Neumaier] {
+        let boys = BoysFunction::builder().summation(summation).build();
+        for t in [5.0, 50.0].iter() {
+            let id = BenchmarkId::new(format!("{summation:?}"), t);
+            group.bench_with_input(id, t, |b, &t| {
+                b.iter(|| boys.eval(black_box(2), black_box(t)));
+            });
+        }
+    }
+
+    group.finish();
+}
+
+/// The piecewise Padé evaluator against `eval` in the mid range.
+fn bench_pade(c: &mut Criterion) {
+    let boys = BoysFunction::new(None);
+
+    let mut group = c.benchmark_group("pade");
+
+    for t in [5.0, 50.0, 100.0].iter() {
+        group.bench_with_input(BenchmarkId::new("eval", t), t, |b, &t| {
+            b.iter(|| boys.eval(black_box(2), black_box(t)));
+        });
+        group.bench_with_input(BenchmarkId::new("pade", t), t, |b, &t| {
+            b.iter(|| boys.eval_pade(black_box(2), black_box(t)));
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_boys_single,
+    bench_boys_array,
+    bench_boys_mixed,
+    bench_erf_seed,
+    bench_series_reciprocals,
+    bench_array_paths,
+    bench_grid,
+    bench_cached_eval,
+    bench_cached_batch,
+    bench_realistic_mix,
+    bench_f0,
+    bench_f0_batch,
+    bench_branchless,
+    bench_summation,
+    bench_pade
+);
 criterion_main!(benches);
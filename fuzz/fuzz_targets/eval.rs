@@ -0,0 +1,45 @@
+#![no_main]
+
+use boys::BoysFunction;
+use libfuzzer_sys::fuzz_target;
+
+// Orders above this would make `eval_array` allocate gigabytes, which is an
+// out-of-memory abort rather than a bug in the evaluator.
+const MAX_M: i32 = 4096;
+
+// Input layout: a little-endian `i32` order followed by a little-endian `f64`
+// argument. Shorter inputs are ignored.
+fuzz_target!(|data: &[u8]| {
+    if data.len() < 12 {
+        return;
+    }
+    let m = i32::from_le_bytes(data[0..4].try_into().unwrap());
+    let t = f64::from_le_bytes(data[4..12].try_into().unwrap());
+
+    // Negative orders are invalid input.
+    if !(0..=MAX_M).contains(&m) {
+        return;
+    }
+
+    let boys = BoysFunction::new(None);
+    // Debug builds, as cargo-fuzz makes by default, reject NaN `t` in `eval`.
+    if !(cfg!(debug_assertions) && t.is_nan()) {
+        check(m, t, boys.eval(m, t));
+    }
+
+    let values = boys.eval_array(m, t);
+    assert_eq!(values.len(), m as usize + 1);
+    for (k, &value) in values.iter().enumerate() {
+        check(k as i32, t, value);
+    }
+});
+
+// A NaN argument propagates to a NaN result; any other argument must give a
+// finite value.
+fn check(m: i32, t: f64, value: f64) {
+    if t.is_nan() {
+        assert!(value.is_nan(), "F_{m}(NaN) = {value}, expected NaN");
+    } else {
+        assert!(value.is_finite(), "F_{m}({t:e}) = {value}, expected finite");
+    }
+}
@@ -0,0 +1,60 @@
+"""Generate src/erf_table.rs, the polynomial table behind eval_f0_batch.
+
+F_0(t) = h(sqrt(t)) with h(x) = sqrt(pi) erf(x) / (2x), which is smooth and
+even, so it is tabulated in x on [0, 6) in segments of width 1/4. Each
+segment holds the Chebyshev fit of h in u = x - lo over [0, 1/4], in
+ascending powers of u, of the lowest degree whose worst relative error at
+201 points across the segment is below 2^-56. From x = 6 erf(x) rounds to 1
+and h(x) = sqrt(pi) / (2x).
+
+Requires mpmath:  python3 gen_erf_table.py > src/erf_table.rs
+"""
+from mpmath import mp, mpf, erf, sqrt, pi, chebyfit, polyval
+
+mp.dps = 40
+WIDTH = mpf(1) / 4
+SEGMENTS = 24
+DEGREE = 13
+TOL = mpf(2) ** -56
+
+
+def h(x):
+    if x == 0:
+        return mpf(1)
+    return sqrt(pi) * erf(x) / (2 * x)
+
+
+def fit(lo, degree):
+    coeffs = chebyfit(lambda u: h(lo + u), [0, WIDTH], degree + 1)
+    worst = max(abs(polyval(coeffs, WIDTH * i / 200) / h(lo + WIDTH * i / 200) - 1)
+                for i in range(201))
+    return coeffs[::-1], worst
+
+
+rows = []
+for k in range(SEGMENTS):
+    lo = k * WIDTH
+    for degree in range(6, DEGREE + 1):
+        coeffs, worst = fit(lo, degree)
+        if worst < TOL:
+            break
+    else:
+        raise SystemExit(f"segment {k}: degree {DEGREE} misses {TOL}")
+    rows.append(coeffs)
+
+width = max(len(row) for row in rows)
+rows = [row + [mpf(0)] * (width - len(row)) for row in rows]
+
+print("// Generated by gen_erf_table.py; do not edit.")
+print()
+print("/// Segments per unit of `x = sqrt(t)`; segment `k` starts at `k / 4`.")
+print("pub const ERF_SEGMENTS_PER_UNIT: f64 = 4.0;")
+print()
+print("/// Row `k`: `sqrt(pi) erf(x) / (2x)` for `x` in `[k / 4, (k + 1) / 4)`, as a")
+print("/// polynomial in `u = x - k / 4` with coefficients in ascending powers,")
+print("/// zero-padded to the longest row.")
+print("#[rustfmt::skip]")
+print(f"pub const ERF_SEGMENTS: [[f64; {width}]; {SEGMENTS}] = [")
+for row in rows:
+    print(f"    [{', '.join(repr(float(c)) for c in row)}],")
+print("];")
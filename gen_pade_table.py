@@ -0,0 +1,70 @@
+"""Generate src/pade_table.rs, the piecewise Pade table behind eval_pade.
+
+Each segment holds the [6/6] Pade approximant of F_16 about the segment
+centre, in the variable s = (t - centre) / half_width, built from the
+40-digit Taylor coefficients (-1)^k F_{16+k}(centre) / k!. Segments are
+grown greedily from t = 0.1, taking the widest power-of-two width whose
+approximant stays within 2e-14 of F_16 at 41 points across it.
+
+Requires mpmath:  python3 gen_pade_table.py > src/pade_table.rs
+"""
+from mpmath import mp, mpf, hyp1f1, pade, factorial, polyval
+
+mp.dps = 40
+M = 16
+ORDER = 6
+T_MIN, T_MAX = 0.1, 117.0
+TOL = 2e-14
+
+
+def boys(m, t):
+    t = mpf(t)
+    return hyp1f1(m + mpf(1) / 2, m + mpf(3) / 2, -t) / (2 * m + 1)
+
+
+def approximant(centre, half):
+    taylor = [(-1) ** k * boys(M + k, centre) / factorial(k) * half**k
+              for k in range(2 * ORDER + 1)]
+    p, q = pade(taylor, ORDER, ORDER)
+    return [c / q[0] for c in p], [c / q[0] for c in q]
+
+
+def worst_error(lo, hi, p, q):
+    centre, half = (lo + hi) / 2, (hi - lo) / 2
+    worst = mpf(0)
+    for i in range(41):
+        s = mpf(-1) + mpf(i) / 20
+        den = polyval(q[::-1], s)
+        if den <= 0:
+            return mpf(1)
+        value = polyval(p[::-1], s) / den
+        worst = max(worst, abs(value / boys(M, centre + half * s) - 1))
+    return worst
+
+
+segments = []
+lo = mpf(T_MIN)
+while lo < T_MAX:
+    for width in [64, 32, 16, 8, 4, 2, 1, 0.5]:
+        hi = min(lo + width, mpf(T_MAX))
+        p, q = approximant((lo + hi) / 2, (hi - lo) / 2)
+        if worst_error(lo, hi, p, q) < TOL:
+            break
+    segments.append((lo, hi, p, q))
+    lo = hi
+
+print("// Generated by gen_pade_table.py; do not edit.")
+print()
+print(f"/// Order `m` whose approximants are tabulated.")
+print(f"pub const PADE_ORDER_M: i32 = {M};")
+print()
+print("/// Segment `(lo, hi, p, q)`: on `[lo, hi]`, with `s = (t - centre) / half_width`,")
+print(f"/// `F_{M}(t) = p(s) / q(s)`, coefficients in ascending powers and `q[0] = 1`.")
+print("#[rustfmt::skip]")
+print("pub const PADE_SEGMENTS: &[(f64, f64, [f64; 7], [f64; 7])] = &[")
+for lo, hi, p, q in segments:
+    fmt = lambda cs: ", ".join(repr(float(c)) for c in cs)
+    print(f"    (\n        {float(lo)!r},\n        {float(hi)!r},")
+    print(f"        [{fmt(p)}],")
+    print(f"        [{fmt(q)}],\n    ),")
+print("];")
@@ -0,0 +1,35 @@
+// Generated by gen_erf_table.py; do not edit.
+
+/// Segments per unit of `x = sqrt(t)`; segment `k` starts at `k / 4`.
+pub const ERF_SEGMENTS_PER_UNIT: f64 = 4.0;
+
+/// Row `k`: `sqrt(pi) erf(x) / (2x)` for `x` in `[k / 4, (k + 1) / 4)`, as a
+/// polynomial in `u = x - k / 4` with coefficients in ascending powers,
+/// zero-padded to the longest row.
+#[rustfmt::skip]
+pub const ERF_SEGMENTS: [[f64; 12]; 24] = [
+    [1.0, 7.424375431707091e-16, -0.33333333333347503, 1.0596886835305413e-11, 0.09999999959022313, 9.358256387841447e-09, -0.023809659463586538, 1.2967140388985363e-06, 0.004621339457841643, 3.5149728055499736e-05, -0.0008527461888627999, 0.00015003642699005704],
+    [0.9795515487210233, -0.16055394363019035, -0.2971972882926959, 0.09280724655376282, 0.07890643976193959, -0.031844730548262166, -0.016305600401909207, 0.007918609028094395, 0.0026967032095479167, -0.0015529559548536226, -0.0003669749768163117, 0.00026799867842098663],
+    [0.9225620128255849, -0.2875224595083711, -0.2037558640529122, 0.14791146697406102, 0.028677395721666676, -0.04437483983343398, 5.360608906638886e-05, 0.009468562264614294, -0.0011087828003737198, -0.0016639846664834271, 0.000541129897080462, 0.0],
+    [0.8403270276415502, -0.36072560388083663, -0.08881535288973166, 0.15007507188796146, -0.022042962907847265, -0.03352290781640904, 0.012943755902729719, 0.004524777366338732, -0.0033094794204592817, -0.0002902488711688159, 0.0006360393278130202, -0.00012958653124289554],
+    [0.746824132812427, -0.3789446916409845, 0.011065250469505737, 0.11156122992365819, -0.050247989829626534, -0.011065248136800635, 0.015152766828317043, -0.001721976337750415, -0.002513094639018669, 0.0008476938315414489, 0.00022986215499811867, -0.00015385576771220302],
+    [0.6543191545317416, -0.3557662139045107, 0.07500158397181253, 0.0587785189185335, -0.05138972043812103, 0.007661318851998214, 0.008972845124600828, -0.00460519110369913, -0.0003666812353680055, 0.0009493242225619492, -0.0002519881838143834, 0.0],
+    [0.5707922624166007, -0.3102620252364968, 0.10144212559674004, 0.0143490908699811, -0.03591586488951944, 0.015160607797257114, 0.001311553449356655, -0.003679317574461226, 0.00106871273632325, 0.0002680663311551465, -0.00020181072281227381, 0.0],
+    [0.49966571520909175, -0.2587971959000819, 0.10111348955990289, -0.012122100814201505, -0.01743278251307425, 0.013316233532154388, -0.0030698499681686767, -0.0013169905854457588, 0.001135940217750704, -0.00023447785503803365, -3.5173041596340505e-05, 0.0],
+    [0.4410406953812108, -0.21136252824623994, 0.087365625234642, -0.022314567263177966, -0.004105748259298588, 0.007852816727731395, -0.0037227869361769234, 0.00036329508620385934, 0.0005276150439407909, -0.00029910669400480376, 5.621835916130371e-05, 0.0],
+    [0.3933025007241488, -0.1719879045762936, 0.07010935327291204, -0.02260287503243376, 0.002529184820208126, 0.0030737563038856857, -0.0025101497737144898, 0.000852055968950848, 1.270543605687704e-05, -0.00014523920102129314, 5.322227150695105e-05, 0.0],
+    [0.3543465094470124, -0.14096642212431262, 0.054456114713299315, -0.01882241619744349, 0.004472413703648634, 0.00037958529203548015, -0.0011439572045629232, 0.0006504829524258185, -0.0001707040873445517, -1.2091669711386826e-05, 1.9968579691323677e-05, 0.0],
+    [0.32223190967130355, -0.1169863036324184, 0.042020899366080486, -0.0143907522112948, 0.004183027039195379, -0.0006330025091226246, -0.0003039172930629872, 0.0003187913113500889, -0.0001452688433515745, 3.2613565364650306e-05, 0.0, 0.0],
+    [0.2954024494198404, -0.09842634653858458, 0.032685372375440475, -0.01066201671735402, 0.003245481059435642, -0.0007815297653681848, 4.2484976445004044e-05, 0.00010122618121637708, -7.361922615787304e-05, 2.8924402629684035e-05, -5.802936555413488e-06, 0.0],
+    [0.27268403452730555, -0.08389482043910254, 0.025787922804133667, -0.007881351067259207, 0.002346887912013107, -0.0006365521223037067, 0.00012400907261439037, 8.144597032812342e-06, -2.4523383735887222e-05, 1.3914883041528877e-05, -3.881943771219763e-06, 0.0],
+    [0.25320750482827775, -0.0723436342031126, 0.020664824655245207, -0.005893526083714098, 0.001666718351480569, -0.0004554191632758355, 0.00011049273571705382, -1.7165658417792912e-05, -2.630127917273598e-06, 2.4355885686541924e-06, 0.0, 0.0],
+    [0.2363271532438936, -0.06302036589198808, 0.0168046497556763, -0.004479356502427162, 0.001191224123089658, -0.0003133199685153511, 7.9013578786445e-05, -1.7317487247724563e-05, 2.318123356046437e-06, 1.5636824778021265e-07, 0.0, 0.0],
+    [0.22155672794739223, -0.05538915385305445, 0.013847175928098048, -0.003461503266632262, 0.0008648319093078337, -0.00021542511575145458, 5.295911913770121e-05, -1.2410123013187406e-05, 2.4978622978000567e-06, -3.1440861076014977e-07, 0.0, 0.0],
+    [0.20852398207358339, -0.049064463003844996, 0.011544565223067517, -0.002716328872373688, 0.0006390572081396955, -0.00015024381112895216, 3.5197862180364255e-05, -8.11937673381805e-06, 1.7566474880967931e-06, -2.850382467024629e-07, 0.0, 0.0],
+    [0.19693931672855813, -0.043764292249628856, 0.009725396672460364, -0.002161194563416512, 0.0004802554167323508, -0.00010670664025835177, 2.3689058041892845e-05, -5.232667541327771e-06, 1.1168137541243681e-06, -1.8813118272019136e-07, 0.0, 0.0],
+    [0.18657408956555285, -0.03927875566455023, 0.00826921155990909, -0.00174088615180812, 0.00036650122589445416, -7.715609121618344e-05, 1.6239753415201413e-05, -3.410991165924374e-06, 6.993434785303046e-07, -1.1564381944789088e-07, 0.0, 0.0],
+    [0.1772453850902791, -0.03544907701527686, 0.007089815389021454, -0.0014179630264425159, 0.0002835923731905893, -5.671684588253556e-05, 1.1333388277635892e-05, -2.2280369741668267e-06, 3.6363745918985723e-07, 0.0, 0.0, 0.0],
+    [0.1688051286576491, -0.03215335783934737, 0.006124449111094967, -0.0011665617280395481, 0.000222202147942223, -4.232331431269539e-05, 8.055540612827885e-06, -1.5105607447320053e-06, 2.3693807482163679e-07, 0.0, 0.0, 0.0],
+    [0.16113216826413662, -0.02929675786619287, 0.005326683248268271, -0.0009684878606950958, 0.0001760886526234321, -3.2015555339956717e-05, 5.817193746008883e-06, -1.0425804717802569e-06, 1.57369521937589e-07, 0.0, 0.0, 0.0],
+    [0.1541264218178709, -0.026804595098759047, 0.004661668712786062, -0.0008107249919829068, 0.0001409956192885117, -2.452061380138759e-05, 4.261987915197891e-06, -7.31457531992673e-07, 1.0639373745194034e-07, 0.0, 0.0, 0.0],
+];
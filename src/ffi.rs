@@ -0,0 +1,91 @@
+//! C ABI entry points for loading the library as a `cdylib`.
+
+use std::sync::OnceLock;
+
+use crate::boys_impl::BoysFunction;
+
+/// Version of the C ABI exported by this library. Bump it whenever an
+/// exported signature changes, so dynamically loaded consumers can reject
+/// a library they were not built against.
+pub const BOYS_ABI_VERSION: u32 = 2;
+
+/// Returns `BOYS_ABI_VERSION`, for checking compatibility at load time.
+#[no_mangle]
+pub extern "C" fn boys_abi_version() -> u32 {
+    BOYS_ABI_VERSION
+}
+
+/// The default-tolerance evaluator behind every entry point, built on first
+/// use.
+fn evaluator() -> &'static BoysFunction {
+    static BOYS: OnceLock<BoysFunction> = OnceLock::new();
+    BOYS.get_or_init(|| BoysFunction::new(None))
+}
+
+/// Returns `F_m(t)` from the default evaluator. NaN `t` gives NaN in every
+/// build, since a debug-build panic cannot unwind into C.
+#[no_mangle]
+pub extern "C" fn boys_eval(m: i32, t: f64) -> f64 {
+    if t.is_nan() {
+        return f64::NAN;
+    }
+    evaluator().eval(m, t)
+}
+
+/// Writes `F_0(t)..=F_mmax(t)` into `out[..=mmax]` from the default
+/// evaluator and returns `0`, or returns `-1` without writing when `out` is
+/// null or `len < mmax + 1`. A negative `mmax` writes nothing and returns
+/// `0`.
+///
+/// # Safety
+///
+/// Unless it is null, `out` must be valid for writes of `len` `f64` values.
+#[no_mangle]
+pub unsafe extern "C" fn boys_eval_array(mmax: i32, t: f64, out: *mut f64, len: usize) -> i32 {
+    let needed = usize::try_from(mmax).map_or(0, |mmax| mmax + 1);
+    if out.is_null() || len < needed {
+        return -1;
+    }
+    let out = std::slice::from_raw_parts_mut(out, len);
+    evaluator().eval_array_into(mmax, t, out);
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_abi_version() {
+        assert_ne!(boys_abi_version(), 0);
+        assert_eq!(boys_abi_version(), BOYS_ABI_VERSION);
+    }
+
+    #[test]
+    fn test_boys_eval() {
+        let boys = BoysFunction::new(None);
+        for (m, t) in [(0, 0.0), (2, 0.05), (5, 30.0), (10, 150.0)] {
+            assert_eq!(boys_eval(m, t), boys.eval(m, t));
+        }
+        assert!(boys_eval(3, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_boys_eval_array() {
+        let boys = BoysFunction::new(None);
+        let mut out = [0.0_f64; 8];
+        for t in [0.05, 5.0, 150.0] {
+            let status = unsafe { boys_eval_array(6, t, out.as_mut_ptr(), out.len()) };
+            assert_eq!(status, 0);
+            assert_eq!(out[..7], boys.eval_array(6, t)[..]);
+        }
+        out = [1.0; 8];
+        assert_eq!(unsafe { boys_eval_array(8, 5.0, out.as_mut_ptr(), 8) }, -1);
+        assert_eq!(out, [1.0; 8]);
+        assert_eq!(
+            unsafe { boys_eval_array(2, 5.0, std::ptr::null_mut(), 8) },
+            -1
+        );
+        assert_eq!(unsafe { boys_eval_array(-1, 5.0, out.as_mut_ptr(), 0) }, 0);
+    }
+}
@@ -1,3 +1,29 @@
 pub mod boys_impl;
+mod erf_table;
+pub mod ffi;
+mod pade_table;
 
-pub use boys_impl::{BoysFunction, BoysFunctionCached};
+pub use boys_impl::{
+    array_from_bytes, boys_at_zero, check_array, predicted_iterations, self_test, sort_ts, BoysAtT,
+    BoysAux, BoysError, BoysFunction, BoysFunctionBuilder, BoysFunctionCached, BoysMethod,
+    BoysOrder, BoysRoundedCache, BoysValues, BoysWorkspace, DispatchPlan, F0Formula, LibmFunctions,
+    SpecialFunctions, Summation, UnitScale,
+};
+
+#[cfg(feature = "highprec")]
+pub use boys_impl::{eval_rational, BigFloat};
+
+#[cfg(feature = "stats")]
+pub use boys_impl::{BoysStats, BoysStatsSnapshot, ITERATION_BUCKETS};
+
+#[cfg(feature = "interval")]
+pub use boys_impl::Interval;
+
+#[cfg(feature = "op-counts")]
+pub use boys_impl::{CountingBoys, OpCounts};
+
+#[cfg(feature = "debug-poison")]
+pub use boys_impl::POISON;
+
+#[cfg(feature = "autodiff")]
+pub use boys_impl::Dual;
@@ -1,6 +1,78 @@
 use boys::boys_impl::BoysFunction;
+use clap::{Parser, Subcommand, ValueEnum};
+
+#[derive(Parser)]
+#[command(name = "boys", about = "Evaluate and tabulate the Boys function F_m(t)")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Print F_m(t) for a single order
+    Eval {
+        #[arg(long)]
+        m: i32,
+        #[arg(long)]
+        t: f64,
+        /// Series convergence tolerance
+        #[arg(long)]
+        epsilon: Option<f64>,
+    },
+    /// Print F_0(t) through F_mmax(t)
+    Table {
+        #[arg(long)]
+        mmax: i32,
+        #[arg(long)]
+        t: f64,
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Series convergence tolerance
+        #[arg(long)]
+        epsilon: Option<f64>,
+    },
+    /// Run the demonstration and timing loop
+    Demo,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Text,
+    Csv,
+}
 
 fn main() {
+    match Cli::parse().command {
+        Command::Eval { m, t, epsilon } => {
+            println!("{}", BoysFunction::new(epsilon).eval(m, t));
+        }
+        Command::Table {
+            mmax,
+            t,
+            format,
+            epsilon,
+        } => {
+            let values = BoysFunction::new(epsilon).eval_array(mmax, t);
+            match format {
+                Format::Text => {
+                    for (m, value) in values.iter().enumerate() {
+                        println!("F_{}({}) = {:.16e}", m, t, value);
+                    }
+                }
+                Format::Csv => {
+                    println!("m,t,value");
+                    for (m, value) in values.iter().enumerate() {
+                        println!("{},{},{}", m, t, value);
+                    }
+                }
+            }
+        }
+        Command::Demo => demo(),
+    }
+}
+
+fn demo() {
     println!("Boys Function Rust Implementation");
     println!("====================================\n");
 
@@ -0,0 +1,256 @@
+// Generated by gen_pade_table.py; do not edit.
+
+/// Order `m` whose approximants are tabulated.
+pub const PADE_ORDER_M: i32 = 16;
+
+/// Segment `(lo, hi, p, q)`: on `[lo, hi]`, with `s = (t - centre) / half_width`,
+/// `F_16(t) = p(s) / q(s)`, coefficients in ascending powers and `q[0] = 1`.
+#[rustfmt::skip]
+pub const PADE_SEGMENTS: &[(f64, f64, [f64; 7], [f64; 7])] = &[
+    (
+        0.1,
+        1.1,
+        [0.017219928462083683, -0.003973313686190355, 0.0004192422860192304, -2.6086372402393896e-05, 1.0190423481981689e-06, -2.3979043965617702e-08, 2.696273851412236e-10],
+        [1.0, 0.2397912184928402, 0.026091337050269815, 0.0016624259273744775, 6.606804225458643e-05, 1.5719476146824996e-06, 1.7767436666463188e-08],
+    ),
+    (
+        1.1,
+        2.1,
+        [0.006730125040013839, -0.0015445386045582004, 0.00016221638400207858, -1.0052899579628871e-05, 3.9132101440088567e-07, -9.179389452811426e-09, 1.0292843534154352e-10],
+        [1.0, 0.2394216581071032, 0.02600768835355993, 0.0016540988106448918, 6.560774410547529e-05, 1.5576436723780674e-06, 1.7564371364483178e-08],
+    ),
+    (
+        2.1,
+        3.1,
+        [0.0026392713622181425, -0.000602058746575445, 6.290696530620347e-05, -3.8811909464107815e-06, 1.5049548813474176e-07, -3.518221386600228e-09, 3.9330707463696496e-11],
+        [1.0, 0.23902888605202724, 0.02591882010730621, 0.001645256046802716, 6.51191958645771e-05, 1.5424710032299027e-06, 1.7349124815821522e-08],
+    ),
+    (
+        3.1,
+        4.1,
+        [0.0010388741064891825, -0.00023538554956132078, 2.4454134598037e-05, -1.501363989826725e-06, 5.796904386168967e-08, -1.350139614707858e-09, 1.504390734922761e-11],
+        [1.0, 0.2386110926128941, 0.0258243308700482, 0.0016358586421065479, 6.460030588397231e-05, 1.5263669720970003e-06, 1.7120847853643833e-08],
+    ),
+    (
+        4.1,
+        5.1,
+        [0.00041060891384764217, -9.232952147730372e-05, 9.530984784316148e-06, -5.819814308824329e-07, 2.2365828429626924e-08, -5.187976611661169e-10, 5.7600611638476346e-12],
+        [1.0, 0.2381663306023459, 0.02572378970269533, 0.0016258648679713598, 6.40488451957996e-05, 1.5092652088504733e-06, 1.687864758392979e-08],
+    ),
+    (
+        5.1,
+        6.1,
+        [0.00016303152478024368, -3.634579726935388e-05, 3.725164040664937e-06, -2.2609596198345928e-07, 8.644191854805197e-09, -1.9961682623512864e-10, 2.2076636090485032e-12],
+        [1.0, 0.23769251058785013, 0.025616735350669585, 0.001615230216337261, 6.346244780018151e-05, 1.4910957300995778e-06, 1.662159117625722e-08],
+    ),
+    (
+        6.1,
+        7.1,
+        [6.505930910076156e-05, -1.4363795820700174e-05, 1.4604071041826808e-06, -8.804383787637541e-08, 3.3469462381670005e-09, -7.691120204380149e-11, 8.469847853458925e-13],
+        [1.0, 0.2371873977326259, 0.02550267580684337, 0.0016039073974275246, 6.283861374218252e-05, 1.4717851687881536e-06, 1.6348711607535573e-08],
+    ),
+    (
+        7.1,
+        8.1,
+        [2.6108856376089316e-05, -5.7010078755816304e-06, 5.74422335338341e-07, -3.437105995056067e-08, 1.2983497371858179e-09, -2.9674631516923444e-11, 3.252724146335602e-13],
+        [1.0, 0.2366486107835155, 0.02538108836106583, 0.0015918463898201244, 6.217471555096575e-05, 1.4512571322743191e-06, 1.6059015721265148e-08],
+    ),
+    (
+        8.1,
+        9.1,
+        [1.0543510620396418e-05, -2.273456891529131e-06, 2.2674371297760704e-07, -1.3453740881592612e-08, 5.046442327863065e-10, -1.14654052473807e-11, 1.2503529248715274e-13],
+        [1.0, 0.23607362380596125, 0.02525142024622411, 0.0015789945524027067, 6.14680085721257e-05, 1.42943270810671e-06, 1.575149495075826e-08],
+    ),
+    (
+        9.1,
+        10.1,
+        [4.287618059713478e-06, -9.113410228272997e-07, 8.984909546755634e-08, -5.281049289780031e-09, 1.965435009282488e-10, -4.436088300482133e-12, 4.810689746614026e-14],
+        [1.0, 0.2354597713064757, 0.02511308998359374, 0.0015652968059178757, 6.071564558633891e-05, 1.4062311321853427e-06, 1.542513899558143e-08],
+    ),
+    (
+        10.1,
+        11.1,
+        [1.7572523423004087e-06, -3.674207433968742e-07, 3.57524008455861e-08, -2.0792027278251573e-09, 7.670720868731188e-11, -1.7187273069791524e-12, 1.8524014638376937e-14],
+        [1.0, 0.2348042573642544, 0.02496548950219061, 0.0015506958873675498, 5.991469581329077e-05, 1.3815706245155968e-06, 1.5078952611517412e-08],
+    ),
+    (
+        11.1,
+        12.1,
+        [7.264988169907287e-07, -1.4906957264969175e-07, 1.4290876488758062e-08, -8.211935324303949e-10, 3.000095082141722e-11, -6.667868046845624e-13, 7.13779687115247e-15],
+        [1.0, 0.23410416925327565, 0.024807987041042288, 0.001535132671733871, 5.906216785154202e-05, 1.3553693805189486e-06, 1.471197543949411e-08],
+    ),
+    (
+        12.1,
+        13.1,
+        [3.0328938593925504e-07, -6.09027235912796e-08, 5.740291645372245e-09, -3.25414611490887e-10, 1.1758773239686487e-11, -2.590008577700228e-13, 2.7518376842548055e-15],
+        [1.0, 0.23335649565106276, 0.024639930708704398, 0.0015185465391513526, 5.815503513955404e-05, 1.327546676389003e-06, 1.4323304404100104e-08],
+    ),
+    (
+        13.1,
+        14.1,
+        [1.279919293729935e-07, -2.5073378519757056e-08, 2.317922667414196e-09, -1.294009400349801e-10, 4.618586158681375e-12, -1.0071374341181274e-13, 1.0612386474639e-15],
+        [1.0, 0.23255814865254112, 0.02446065231219884, 0.0015008757361605703, 5.719026085828079e-05, 1.2980239978994544e-06, 1.3912117573384597e-08],
+    ),
+    (
+        14.1,
+        15.1,
+        [5.466788476436355e-08, -1.0409965595474932e-08, 9.413033569104512e-10, -5.16420795083847e-11, 1.8178030740614466e-12, -3.919758103514876e-14, 4.0926281209018045e-16],
+        [1.0, 0.23170598693126657, 0.024269470563736063, 0.0014820576264219132, 5.616481632551842e-05, 1.2667260206996833e-06, 1.3477697351954765e-08],
+    ),
+    (
+        15.1,
+        16.1,
+        [2.3662499224749632e-08, -4.3621630784019175e-09, 3.845972031295564e-10, -2.0686074684501625e-11, 7.168299734329371e-13, -1.5264487145565372e-14, 1.577652250608002e-16],
+        [1.0, 0.2307968334686717, 0.024065691801747592, 0.001462028629060702, 5.507568192136157e-05, 1.2335811338821952e-06, 1.3019449247255495e-08],
+    ),
+    (
+        16.1,
+        17.1,
+        [1.0393080245295239e-08, -1.8464905742481235e-09, 1.5816436313220227e-10, -8.317243288495856e-12, 2.831492661459787e-13, -5.9452674616372786e-15, 6.075711667132392e-17],
+        [1.0, 0.22982747311093857, 0.023848604465813394, 0.0014407234621680893, 5.39198106615171e-05, 1.1985209660706913e-06, 1.2536909797529356e-08],
+    ),
+    (
+        17.1,
+        19.1,
+        [3.1185996715555933e-09, -1.0385802683113616e-09, 1.6904331307809486e-10, -1.7081368417101053e-11, 1.1272650538944231e-12, -4.619251171011054e-14, 9.2598354719372e-16],
+        [1.0, 0.45650649061642506, 0.09398544394128933, 0.01124975532602594, 0.0008328609947852545, 3.655010085487022e-05, 7.530763051563395e-07],
+    ),
+    (
+        19.1,
+        21.1,
+        [6.674665562241087e-10, -1.9995349104509478e-10, 2.986318352300536e-11, -2.819472176259776e-12, 1.7650214374227485e-13, -6.9434985537802976e-15, 1.3486010120192802e-16],
+        [1.0, 0.4518182322801612, 0.09188838661630766, 0.010839971290249533, 0.0007887639413328938, 3.390938821589186e-05, 6.817240828914937e-07],
+    ),
+    (
+        21.1,
+        23.1,
+        [1.547722691898757e-10, -4.07430998209137e-11, 5.4444279311517984e-12, -4.693887874461492e-13, 2.7364078887947014e-14, -1.01952925375543e-15, 1.9008134011009042e-17],
+        [1.0, 0.4464650919544681, 0.08949189851940954, 0.010373079002319964, 0.0007388956010456043, 3.096058912106966e-05, 6.035152109699723e-07],
+    ),
+    (
+        23.1,
+        25.1,
+        [3.9082010902838725e-11, -8.849504883013871e-12, 1.0254998450657333e-12, -7.798765924916061e-14, 4.102370943982018e-15, -1.412380062416326e-16, 2.485596339009289e-18],
+        [1.0, 0.44007968522046276, 0.08663405273466963, 0.009819994884177024, 0.0006806163385824817, 2.758619147363284e-05, 5.165959107175457e-07],
+    ),
+    (
+        25.1,
+        27.1,
+        [1.0756028542420819e-11, -2.074335871821533e-12, 2.0190002284112057e-13, -1.2845859929882087e-14, 5.717781939966065e-16, -1.710349093775001e-17, 2.708738002938282e-19],
+        [1.0, 0.4310969484172737, 0.08269201537274026, 0.009077706768911183, 0.0006051250917164369, 2.3403215024714257e-05, 4.144197534909546e-07],
+    ),
+    (
+        27.1,
+        29.1,
+        [3.216465941663876e-12, -5.468719722030139e-13, 4.544406653347734e-14, -2.3634804722988572e-15, 8.176246831619973e-17, -1.8188409926478652e-18, 2.1090677727569282e-20],
+        [1.0, 0.4137049250986976, 0.07558436919808802, 0.007837090074754409, 0.0004888118084398975, 1.7500315697639426e-05, 2.833609464863061e-07],
+    ),
+    (
+        29.1,
+        33.1,
+        [6.06613647935308e-13, -2.0366290376196865e-13, 3.376066774029789e-14, -3.5283908881469125e-15, 2.451388830595014e-16, -1.0757008926608043e-17, 2.3407027621554025e-19],
+        [1.0, 0.7237047396556473, 0.22787958751460458, 0.040027141887014214, 0.004144246736226091, 0.00024030468302528856, 6.110176415212796e-06],
+    ),
+    (
+        33.1,
+        35.1,
+        [1.3291034776350977e-13, -2.0941150007600784e-14, 1.6453269057231687e-15, -8.25354125172434e-17, 2.796117470722915e-18, -6.101546664346691e-20, 6.765331339281901e-22],
+        [1.0, 0.32614141930847396, 0.04610279400946835, 0.0036286188024918886, 0.00016861924759077336, 4.422874803723238e-06, 5.1839614309900724e-08],
+    ),
+    (
+        35.1,
+        37.1,
+        [5.1907139027596065e-14, -7.620714755163996e-15, 5.580296767880975e-16, -2.6110115978272745e-17, 8.2639644589942335e-19, -1.689081734469464e-20, 1.760511704720441e-22],
+        [1.0, 0.31019332354491486, 0.04176871281242668, 0.0031419131294171026, 0.0001403120987935963, 3.565805972083799e-06, 4.091721984523503e-08],
+    ),
+    (
+        37.1,
+        39.1,
+        [2.1323705166526117e-14, -2.94002571464968e-15, 2.0195426815081545e-16, -8.854923628656175e-18, 2.6239459743972344e-19, -5.018257144444626e-21, 4.8936231799732314e-23],
+        [1.0, 0.29517745370596526, 0.03785269911607166, 0.0027156859757880404, 0.00011590319809056278, 2.82076791207109e-06, 3.103166432610172e-08],
+    ),
+    (
+        39.1,
+        43.1,
+        [6.1058375868351944e-15, -1.5568010199440396e-15, 1.9758823566623062e-16, -1.5990593119860676e-17, 8.735182389940851e-19, -3.075430719455604e-20, 5.5129018301208305e-22],
+        [1.0, 0.5479447917380122, 0.1304453765519623, 0.017377166863310263, 0.0013770851617220191, 6.218242742993064e-05, 1.2664358345887898e-06],
+    ),
+    (
+        43.1,
+        47.1,
+        [1.3189372986220217e-15, -3.0695874030692363e-16, 3.5562090893275205e-17, -2.6269841912835093e-18, 1.3097375151868806e-19, -4.207687132396231e-21, 6.880013610474206e-23],
+        [1.0, 0.4989750143835261, 0.10814483320711177, 0.013111577352972796, 0.0009451225096557747, 3.878104976931697e-05, 7.167110756084216e-07],
+    ),
+    (
+        47.1,
+        51.1,
+        [3.245582129091557e-16, -6.941184322815413e-17, 7.390265698045443e-18, -5.017514287836889e-19, 2.2994177344091674e-20, -6.790901366547435e-22, 1.0208770951237713e-23],
+        [1.0, 0.45823211052658436, 0.09120098861255874, 0.010153428500063212, 0.0006720084822489018, 2.5316330570729667e-05, 4.2956404783605546e-07],
+    ),
+    (
+        51.1,
+        55.1,
+        [8.914102720910296e-17, -1.7627292672464595e-17, 1.73532581016679e-18, -1.0893859016538166e-19, 4.616237127840847e-21, -1.2606133418561665e-22, 1.7523547651675417e-24],
+        [1.0, 0.4237228001292214, 0.07798219138875188, 0.00802811729638997, 0.0004913485376343412, 1.7117836071550847e-05, 2.686264675260172e-07],
+    ),
+    (
+        55.1,
+        59.1,
+        [2.689313590909876e-17, -4.9453343631858944e-18, 4.527265996908308e-19, -2.6428973385324915e-20, 1.0414268563816355e-21, -2.6446198737213398e-23, 3.4185565848668274e-25],
+        [1.0, 0.39404510093079015, 0.06744116268740515, 0.006456704209721973, 0.0003675000713881041, 1.1906724769963183e-05, 1.7377010105687585e-07],
+    ),
+    (
+        59.1,
+        63.1,
+        [8.799485170676127e-18, -1.5121853126568967e-18, 1.2937150239453789e-19, -7.057905075646125e-21, 2.599062874157061e-22, -6.167984352794161e-24, 7.450993431412308e-26],
+        [1.0, 0.3682489058362637, 0.0589001279137568, 0.005269845661998776, 0.0002803111931004702, 8.48733930208408e-06, 1.1575779313367394e-07],
+    ),
+    (
+        63.1,
+        67.1,
+        [3.090657793265806e-18, -4.984930403166035e-19, 4.002700778841013e-20, -2.049513223535645e-21, 7.083568246126753e-23, -1.5777526170691276e-24, 1.788834333063201e-26],
+        [1.0, 0.34562216904298915, 0.0518843594920688, 0.004356905754514614, 0.0002175107247218204, 6.181183173444285e-06, 7.912426762793449e-08],
+    ),
+    (
+        67.1,
+        71.1,
+        [1.155445461544516e-18, -1.7557419094963374e-19, 1.3281825132796923e-20, -6.407054563207037e-22, 2.0862332159152408e-23, -4.377768262190212e-25, 4.676135388975451e-27],
+        [1.0, 0.32561506006486596, 0.04605133563790689, 0.0036432317384887283, 0.00017135316759548473, 4.587603294500267e-06, 5.532566227866259e-08],
+    ),
+    (
+        71.1,
+        75.1,
+        [4.565627894338791e-19, -6.55801538261745e-20, 4.68953961661509e-21, -2.138412932651821e-22, 6.581982262447695e-24, -1.3055915367622547e-25, 1.3182638564985559e-27],
+        [1.0, 0.3077975389489186, 0.04114940382309392, 0.0030772924181599807, 0.00013681532762226508, 3.4624946549280137e-06, 3.947212403906707e-08],
+    ),
+    (
+        75.1,
+        83.1,
+        [1.242296481023403e-19, -3.2981322492818e-20, 4.3590990519180466e-21, -3.6739140748502425e-22, 2.090090180478694e-23, -7.662783210417215e-25, 1.4300540565945955e-26],
+        [1.0, 0.5689001265206817, 0.14057410323875946, 0.019430385956495714, 0.0015966815266988314, 7.468674654427327e-05, 1.5736777622802538e-06],
+    ),
+    (
+        83.1,
+        91.1,
+        [2.5341812604574692e-20, -6.1099662995910295e-21, 7.333745167001423e-22, -5.613276055851849e-23, 2.9000852182788098e-24, -9.655852047025659e-26, 1.6364956178213225e-27],
+        [1.0, 0.5166475315736703, 0.11593697869589938, 0.014553130123360902, 0.001086054486821207, 4.613549484792965e-05, 8.828070196746867e-07],
+    ),
+    (
+        91.1,
+        99.1,
+        [5.945093691654779e-21, -1.3127967142455106e-21, 1.443185375568593e-22, -1.0116967231465523e-23, 4.787208469147583e-25, -1.459821720349895e-26, 2.266009380143449e-28],
+        [1.0, 0.4731861198738211, 0.0972517118562973, 0.0111807085484984, 0.0007641914360172539, 2.9731948614762402e-05, 5.210646444928738e-07],
+    ),
+    (
+        99.1,
+        107.1,
+        [1.5680837546824566e-21, -3.1939630308760026e-22, 3.2387421616324866e-23, -2.0942400010556004e-24, 9.140717754364889e-26, -2.571103927027951e-27, 3.6813182641848626e-29],
+        [1.0, 0.4364694471387003, 0.0827448471341333, 0.008774752622048406, 0.0005532094281601808, 1.985329664590368e-05, 3.209391633673405e-07],
+    ),
+    (
+        107.1,
+        117.0,
+        [3.9704664292559206e-22, -9.208611899177888e-23, 1.0632433417725472e-23, -7.828431565929063e-25, 3.8906361698141425e-26, -1.246097327480634e-27, 2.0315509116030608e-29],
+        [1.0, 0.49698795180722893, 0.10728153578168094, 0.012954209541509533, 0.0009299457652790478, 3.800079583017796e-05, 6.994791735809195e-07],
+    ),
+];
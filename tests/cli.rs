@@ -0,0 +1,35 @@
+use std::process::Command;
+
+fn run(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_boys"))
+        .args(args)
+        .output()
+        .expect("failed to run boys binary");
+    assert!(output.status.success(), "boys {:?} failed", args);
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn eval_prints_value() {
+    let stdout = run(&["eval", "--m", "1", "--t", "5.0"]);
+    let value: f64 = stdout.trim().parse().unwrap();
+    assert!((value - 0.0388974363).abs() < 1e-9);
+}
+
+#[test]
+fn table_csv() {
+    let stdout = run(&["table", "--mmax", "5", "--t", "5.0", "--format", "csv"]);
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), 7);
+    assert_eq!(lines[0], "m,t,value");
+    let fields: Vec<&str> = lines[1].split(',').collect();
+    assert_eq!(fields[0], "0");
+    let f0: f64 = fields[2].parse().unwrap();
+    assert!((f0 - 0.3957123096).abs() < 1e-9);
+}
+
+#[test]
+fn demo_runs() {
+    let stdout = run(&["demo"]);
+    assert!(stdout.contains("Boys Function Rust Implementation"));
+}